@@ -0,0 +1,364 @@
+use crate::config::{Config, CONFIG_FILE};
+use crate::due_date;
+use crate::kanban_board::DB_FILE;
+use crate::store::Store;
+use crate::task::{DueDate, Priority, Task, TimeEntry};
+use chrono::Local;
+use csv::{Reader, Writer};
+use std::collections::{HashMap, HashSet};
+use std::{fs, io, path::Path};
+use uuid::Uuid;
+
+/// The CLI-facing side of the board. Tasks live in the same SQLite store as
+/// the TUI (see `crate::store`), grouped into columns declared by
+/// `.kanban_config.json`.
+pub struct KanbanApp {
+    pub config: Config,
+    store: Store,
+}
+
+impl KanbanApp {
+    pub fn init(dir: &str) -> io::Result<()> {
+        let config_path = format!("{}/{}", dir, CONFIG_FILE);
+        if Path::new(&config_path).exists() {
+            println!("Kanban already initialized in this directory.");
+            return Ok(());
+        }
+        let config = Config::default();
+        fs::create_dir_all(dir)?;
+        fs::write(&config_path, serde_json::to_string_pretty(&config)?)?;
+        Store::open(&format!("{}/{}", dir, DB_FILE)).map_err(store_err)?;
+        println!("Kanban initialized in {}", dir);
+        Ok(())
+    }
+
+    pub fn new(config_path: &str) -> Self {
+        let config = Config::load_or_default(config_path);
+        let store = Store::open(DB_FILE).expect("failed to open kanban database");
+        Self { config, store }
+    }
+
+    pub fn add_task_interactive(
+        status: &str,
+        tag: &str,
+        description: &str,
+        priority: Priority,
+        due_date_input: Option<&str>,
+    ) -> io::Result<()> {
+        let today = Local::now().date_naive();
+        let due_date = due_date_input
+            .map(|raw| {
+                due_date::parse_due_date(raw, today)
+                    .map(|date| DueDate { raw: raw.to_string(), date })
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("couldn't understand due date \"{}\"", raw)))
+            })
+            .transpose()?;
+
+        let app = Self::new(CONFIG_FILE);
+        let id = Uuid::new_v4();
+        let task = Task {
+            id,
+            description: description.to_string(),
+            created_at: today.format("%Y-%m-%d").to_string(),
+            due_date,
+            status: status.to_string(),
+            priority,
+            tags: vec![tag.to_string()],
+            time_entries: Vec::new(),
+            dependencies: HashSet::new(),
+        };
+        app.store.upsert_task(&task).map_err(store_err)
+    }
+
+    /// Appends a `TimeEntry` dated today to the task matching `task_id` and
+    /// persists it. `duration` accepts shorthand like `1h30m` or `45m`.
+    pub fn log_time(task_id: &str, duration: &str, message: Option<String>) -> io::Result<()> {
+        let id = Uuid::parse_str(task_id)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "task-id must be a valid UUID"))?;
+        let (hours, minutes) = parse_duration(duration)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid duration"))?;
+
+        let app = Self::new(CONFIG_FILE);
+        let mut task = app
+            .store
+            .find_task(id)
+            .map_err(store_err)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "task not found"))?;
+
+        task.time_entries
+            .push(TimeEntry::new(Local::now().date_naive(), hours, minutes, message));
+        app.store.upsert_task(&task).map_err(store_err)
+    }
+
+    pub fn show_board(&self) {
+        for column in &self.config.columns {
+            println!("== {} ==", column.name);
+            for task in self.tasks_by_status(&column.name) {
+                println!("[#{}] {}", task.id, task.description);
+            }
+        }
+    }
+
+    pub fn list_tasks(&self) {
+        let tasks = self.all_tasks();
+        for column in &self.config.columns {
+            println!("{}:", column.name);
+            for task in tasks.iter().filter(|t| t.status == column.name) {
+                match self.first_unfinished_dependency(task, &tasks) {
+                    Some(blocker) => println!(
+                        "- [#{}] {} ({}) \u{26d4} blocked by #{}",
+                        task.id,
+                        task.description,
+                        task.tags.join(", "),
+                        blocker
+                    ),
+                    None => println!("- [#{}] {} ({})", task.id, task.description, task.tags.join(", ")),
+                }
+            }
+        }
+    }
+
+    /// Finds a dependency of `task` that hasn't reached the workflow's final
+    /// column yet, the same check `KanbanBoard::move_task` uses to gate entry
+    /// into it.
+    fn first_unfinished_dependency(&self, task: &Task, tasks: &[Task]) -> Option<Uuid> {
+        let done = self.config.columns.last().map(|c| c.name.as_str()).unwrap_or("DONE");
+        task.dependencies
+            .iter()
+            .find(|dep_id| {
+                tasks
+                    .iter()
+                    .find(|t| t.id == **dep_id)
+                    .map(|t| t.status != done)
+                    .unwrap_or(false)
+            })
+            .copied()
+    }
+
+    pub fn list_tags(&self) {
+        let tags: Vec<String> = self.all_tasks().into_iter().flat_map(|t| t.tags).collect();
+        println!("Tags: {:?}", tags);
+    }
+
+    pub fn show_stats(&self) {
+        let tasks = self.all_tasks();
+        let mut status_counts: HashMap<String, usize> = HashMap::new();
+        let mut status_time: HashMap<String, (u32, u32)> = HashMap::new();
+        let mut tag_time: HashMap<String, (u32, u32)> = HashMap::new();
+
+        for task in &tasks {
+            *status_counts.entry(task.status.clone()).or_insert(0) += 1;
+            let (hours, minutes) = task.total_logged();
+            add_time(status_time.entry(task.status.clone()).or_insert((0, 0)), hours, minutes);
+            for tag in &task.tags {
+                add_time(tag_time.entry(tag.clone()).or_insert((0, 0)), hours, minutes);
+            }
+        }
+
+        for (status, count) in &status_counts {
+            println!("{}: {} tasks", status, count);
+        }
+
+        println!("Time logged by status:");
+        for (status, (hours, minutes)) in &status_time {
+            println!("  {}: {}h{}m", status, hours, minutes);
+        }
+
+        println!("Time logged by tag:");
+        for (tag, (hours, minutes)) in &tag_time {
+            println!("  {}: {}h{}m", tag, hours, minutes);
+        }
+    }
+
+    /// Writes every task to a CSV file at `path`, returning the number written.
+    pub fn export_csv(&self, path: &str) -> io::Result<usize> {
+        let tasks = self.all_tasks();
+        let mut writer = Writer::from_path(path)?;
+        writer.write_record([
+            "id",
+            "status",
+            "tags",
+            "description",
+            "created_at",
+            "due_date",
+            "due_date_raw",
+            "priority",
+            "time_entries",
+            "dependencies",
+        ])?;
+        for task in &tasks {
+            writer.write_record([
+                task.id.to_string(),
+                task.status.clone(),
+                task.tags.join(", "),
+                task.description.clone(),
+                task.created_at.clone(),
+                task.due_date.as_ref().map_or(String::new(), |d| d.date.to_string()),
+                task.due_date.as_ref().map_or(String::new(), |d| d.raw.clone()),
+                format!("{:?}", task.priority),
+                serde_json::to_string(&task.time_entries).unwrap_or_default(),
+                serde_json::to_string(&task.dependencies).unwrap_or_default(),
+            ])?;
+        }
+        writer.flush()?;
+        Ok(tasks.len())
+    }
+
+    /// Reads tasks from a CSV file at `path` and upserts each into the store
+    /// (matching by id), returning the number imported.
+    pub fn import_csv(&self, path: &str) -> io::Result<usize> {
+        let mut reader = Reader::from_path(path)?;
+        let mut count = 0;
+        for result in reader.records() {
+            let record = result?;
+            let id = Uuid::parse_str(record.get(0).unwrap_or_default())
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid id"))?;
+            let priority = match record.get(7) {
+                Some("Medium") => Priority::Medium,
+                Some("High") => Priority::High,
+                _ => Priority::Low,
+            };
+            let due_date = match (record.get(5), record.get(6)) {
+                (Some(date), Some(raw)) if !date.is_empty() => {
+                    date.parse().ok().map(|date| DueDate { raw: raw.to_string(), date })
+                }
+                _ => None,
+            };
+            let task = Task {
+                id,
+                description: record.get(3).unwrap_or_default().to_string(),
+                created_at: record.get(4).unwrap_or_default().to_string(),
+                due_date,
+                status: record.get(1).unwrap_or_default().to_string(),
+                priority,
+                tags: record
+                    .get(2)
+                    .unwrap_or_default()
+                    .split(", ")
+                    .filter(|t| !t.is_empty())
+                    .map(String::from)
+                    .collect(),
+                time_entries: record
+                    .get(8)
+                    .and_then(|s| serde_json::from_str(s).ok())
+                    .unwrap_or_default(),
+                dependencies: record
+                    .get(9)
+                    .and_then(|s| serde_json::from_str(s).ok())
+                    .unwrap_or_default(),
+            };
+            self.store.upsert_task(&task).map_err(store_err)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    fn all_tasks(&self) -> Vec<Task> {
+        self.store.all_tasks().unwrap_or_default()
+    }
+
+    fn tasks_by_status(&self, status: &str) -> Vec<Task> {
+        self.all_tasks().into_iter().filter(|t| t.status == status).collect()
+    }
+}
+
+fn store_err(err: rusqlite::Error) -> io::Error {
+    io::Error::other(err)
+}
+
+/// Parses shorthand durations like `1h30m`, `45m`, or `2h`.
+fn parse_duration(input: &str) -> Option<(u16, u16)> {
+    let mut hours = 0u16;
+    let mut minutes = 0u16;
+    let mut number = String::new();
+    for c in input.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+        } else if c == 'h' {
+            hours = number.parse().ok()?;
+            number.clear();
+        } else if c == 'm' {
+            minutes = number.parse().ok()?;
+            number.clear();
+        } else {
+            return None;
+        }
+    }
+    if hours == 0 && minutes == 0 {
+        return None;
+    }
+    Some((hours, minutes))
+}
+
+fn add_time(total: &mut (u32, u32), hours: u32, minutes: u32) {
+    total.0 += hours;
+    total.1 += minutes;
+    total.0 += total.1 / 60;
+    total.1 %= 60;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_then_import_round_trips_priority_time_entries_and_dependencies() {
+        let store = Store::open(":memory:").unwrap();
+        let config = Config::default();
+
+        let dep_id = Uuid::new_v4();
+        let task_id = Uuid::new_v4();
+        let mut dependencies = HashSet::new();
+        dependencies.insert(dep_id);
+        let task = Task {
+            id: task_id,
+            description: "write the report".to_string(),
+            created_at: "2026-07-20".to_string(),
+            due_date: Some(DueDate {
+                raw: "next friday".to_string(),
+                date: "2026-07-31".parse().unwrap(),
+            }),
+            status: "TODO".to_string(),
+            priority: Priority::High,
+            tags: vec!["work".to_string()],
+            time_entries: vec![TimeEntry::new("2026-07-21".parse().unwrap(), 1, 30, Some("draft".to_string()))],
+            dependencies,
+        };
+        store.upsert_task(&task).unwrap();
+        store.upsert_task(&bare_dependency(dep_id)).unwrap();
+
+        let app = KanbanApp { config, store };
+        let path = std::env::temp_dir().join(format!("kanban_test_roundtrip_{}.csv", task_id));
+        let path_str = path.to_str().unwrap();
+
+        app.export_csv(path_str).unwrap();
+        app.import_csv(path_str).unwrap();
+        fs::remove_file(&path).ok();
+
+        let reimported = app
+            .all_tasks()
+            .into_iter()
+            .find(|t| t.id == task_id)
+            .expect("round-tripped task should still be present");
+
+        assert_eq!(reimported.priority, Priority::High);
+        assert_eq!(reimported.time_entries.len(), 1);
+        assert_eq!(reimported.time_entries[0].hours, 1);
+        assert_eq!(reimported.time_entries[0].minutes, 30);
+        assert_eq!(reimported.dependencies, [dep_id].into_iter().collect());
+    }
+
+    fn bare_dependency(id: Uuid) -> Task {
+        Task {
+            id,
+            description: "a dependency".to_string(),
+            created_at: "2026-07-20".to_string(),
+            due_date: None,
+            status: "TODO".to_string(),
+            priority: Priority::Low,
+            tags: Vec::new(),
+            time_entries: Vec::new(),
+            dependencies: HashSet::new(),
+        }
+    }
+}
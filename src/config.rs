@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use std::{fs, io};
+
+pub const CONFIG_FILE: &str = ".kanban_config.json";
+
+/// A single stage in the board's workflow. `wip_limit`, if set, is checked
+/// when a task is moved into this column: the move is refused once the column
+/// holds that many tasks. Tasks added or imported straight into the column are
+/// not checked against it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColumnConfig {
+    pub name: String,
+    #[serde(default)]
+    pub wip_limit: Option<usize>,
+}
+
+/// The team's workflow: an ordered list of columns, e.g.
+/// Backlog -> Review -> Deploy instead of the default TODO/DOING/DONE.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Config {
+    pub columns: Vec<ColumnConfig>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            columns: vec![
+                ColumnConfig {
+                    name: "TODO".to_string(),
+                    wip_limit: None,
+                },
+                ColumnConfig {
+                    name: "DOING".to_string(),
+                    wip_limit: None,
+                },
+                ColumnConfig {
+                    name: "DONE".to_string(),
+                    wip_limit: None,
+                },
+            ],
+        }
+    }
+}
+
+impl Config {
+    pub fn load(path: &str) -> io::Result<Self> {
+        let data = fs::read_to_string(path)?;
+        let config: Self =
+            serde_json::from_str(&data).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if config.columns.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "config must declare at least one column",
+            ));
+        }
+        Ok(config)
+    }
+
+    /// Loads `path`, falling back to the default TODO/DOING/DONE workflow if
+    /// the file is missing, unparsable, or declares no columns at all.
+    pub fn load_or_default(path: &str) -> Self {
+        Self::load(path).unwrap_or_default()
+    }
+}
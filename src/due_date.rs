@@ -0,0 +1,164 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// Resolves a human phrase ("tomorrow", "next friday", "in 3 days", "aug 20")
+/// against `now`, falling back to ISO (`YYYY-MM-DD`) parsing. Returns `None`
+/// if nothing matches.
+pub fn parse_due_date(input: &str, now: NaiveDate) -> Option<NaiveDate> {
+    let trimmed = input.trim();
+    let lower = trimmed.to_lowercase();
+
+    match lower.as_str() {
+        "today" => return Some(now),
+        "tomorrow" => return Some(now + Duration::days(1)),
+        _ => {}
+    }
+
+    if let Some(rest) = lower.strip_prefix("in ") {
+        if let Some(date) = parse_relative(rest, now) {
+            return Some(date);
+        }
+    }
+
+    if let Some(rest) = lower.strip_prefix("next ") {
+        if let Some(weekday) = parse_weekday(rest) {
+            return Some(next_weekday(now, weekday));
+        }
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+        return Some(date);
+    }
+
+    parse_month_day(&lower, now)
+}
+
+fn parse_relative(rest: &str, now: NaiveDate) -> Option<NaiveDate> {
+    let mut parts = rest.split_whitespace();
+    let count: i64 = parts.next()?.parse().ok()?;
+    let unit = parts.next()?;
+    if unit.starts_with("day") {
+        Some(now + Duration::days(count))
+    } else if unit.starts_with("week") {
+        Some(now + Duration::weeks(count))
+    } else {
+        None
+    }
+}
+
+fn parse_weekday(s: &str) -> Option<Weekday> {
+    match s {
+        "monday" => Some(Weekday::Mon),
+        "tuesday" => Some(Weekday::Tue),
+        "wednesday" => Some(Weekday::Wed),
+        "thursday" => Some(Weekday::Thu),
+        "friday" => Some(Weekday::Fri),
+        "saturday" => Some(Weekday::Sat),
+        "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn next_weekday(now: NaiveDate, target: Weekday) -> NaiveDate {
+    let mut date = now + Duration::days(1);
+    while date.weekday() != target {
+        date += Duration::days(1);
+    }
+    date
+}
+
+/// Parses `<month name> <day>` (e.g. "aug 20"), rolling over to next year if
+/// that date has already passed this year.
+fn parse_month_day(lower: &str, now: NaiveDate) -> Option<NaiveDate> {
+    let mut parts = lower.split_whitespace();
+    let month = month_from_str(parts.next()?)?;
+    let day: u32 = parts
+        .next()?
+        .trim_end_matches(|c: char| !c.is_ascii_digit())
+        .parse()
+        .ok()?;
+
+    let date = NaiveDate::from_ymd_opt(now.year(), month, day)?;
+    if date < now {
+        NaiveDate::from_ymd_opt(now.year() + 1, month, day)
+    } else {
+        Some(date)
+    }
+}
+
+fn month_from_str(s: &str) -> Option<u32> {
+    Some(match &s[..s.len().min(3)] {
+        "jan" => 1,
+        "feb" => 2,
+        "mar" => 3,
+        "apr" => 4,
+        "may" => 5,
+        "jun" => 6,
+        "jul" => 7,
+        "aug" => 8,
+        "sep" => 9,
+        "oct" => 10,
+        "nov" => 11,
+        "dec" => 12,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn today_and_tomorrow() {
+        let now = date(2026, 7, 27);
+        assert_eq!(parse_due_date("today", now), Some(now));
+        assert_eq!(parse_due_date("Tomorrow", now), Some(date(2026, 7, 28)));
+    }
+
+    #[test]
+    fn relative_offsets() {
+        let now = date(2026, 7, 27);
+        assert_eq!(parse_due_date("in 3 days", now), Some(date(2026, 7, 30)));
+        assert_eq!(parse_due_date("in 2 weeks", now), Some(date(2026, 8, 10)));
+        assert_eq!(parse_due_date("in 1 day", now), Some(date(2026, 7, 28)));
+    }
+
+    #[test]
+    fn next_weekday_rolls_forward_and_wraps_the_week() {
+        // 2026-07-27 is a Monday.
+        let now = date(2026, 7, 27);
+        assert_eq!(parse_due_date("next friday", now), Some(date(2026, 7, 31)));
+        // "next monday" must not return `now` itself.
+        assert_eq!(parse_due_date("next monday", now), Some(date(2026, 8, 3)));
+    }
+
+    #[test]
+    fn month_day_rolls_over_to_next_year() {
+        let now = date(2026, 7, 27);
+        assert_eq!(parse_due_date("aug 20", now), Some(date(2026, 8, 20)));
+        // Already passed this year, so it should roll to next year.
+        assert_eq!(parse_due_date("jan 1", now), Some(date(2027, 1, 1)));
+    }
+
+    #[test]
+    fn month_day_handles_december_to_january_rollover() {
+        let now = date(2026, 12, 30);
+        assert_eq!(parse_due_date("dec 15", now), Some(date(2027, 12, 15)));
+        assert_eq!(parse_due_date("jan 2", now), Some(date(2027, 1, 2)));
+    }
+
+    #[test]
+    fn iso_fallback() {
+        let now = date(2026, 7, 27);
+        assert_eq!(parse_due_date("2026-09-05", now), Some(date(2026, 9, 5)));
+    }
+
+    #[test]
+    fn unrecognized_input_returns_none() {
+        let now = date(2026, 7, 27);
+        assert_eq!(parse_due_date("whenever", now), None);
+    }
+}
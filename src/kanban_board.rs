@@ -1,9 +1,16 @@
-use crate::task::Task;
+use crate::config::{Config, CONFIG_FILE};
+use crate::due_date;
+use crate::store::Store;
+use crate::task::{DueDate, Priority, Task};
 use chrono::Local;
-use std::{fs, path::Path};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+pub const DB_FILE: &str = ".kanban.db";
 
-#[derive(Debug, Default)]
 pub struct KanbanBoard {
+    store: Store,
+    config: Config,
     pub tasks: Vec<Task>,
     pub selected_status: usize,
     pub selected_task: usize,
@@ -11,59 +18,246 @@ pub struct KanbanBoard {
 
 impl KanbanBoard {
     pub fn new() -> Self {
+        let store = Store::open(DB_FILE).expect("failed to open kanban database");
+        let config = Config::load_or_default(CONFIG_FILE);
         Self {
+            store,
+            config,
             tasks: Vec::new(),
             selected_status: 0,
             selected_task: 0,
         }
     }
 
+    /// The workflow's column names, in order (e.g. `["TODO", "DOING", "DONE"]`
+    /// by default, or whatever `.kanban_config.json` declares).
+    pub fn column_names(&self) -> Vec<&str> {
+        self.config.columns.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    fn status_name(&self, index: usize) -> &str {
+        &self.config.columns[index].name
+    }
+
+    /// Persists every in-memory task back to the database.
     pub fn save_to_file(&self) {
-        let file_path = "kanban_board.json";
-        if let Err(err) = fs::write(
-            file_path,
-            serde_json::to_string_pretty(&self.tasks).unwrap(),
-        ) {
+        if let Err(err) = self.store.replace_all(&self.tasks) {
             eprintln!("Failed to save tasks: {}", err);
         }
     }
 
+    /// Refreshes the in-memory task list from the database.
     pub fn load_from_file(&mut self) {
-        let file_path = "kanban_board.json";
-        if Path::new(file_path).exists() {
-            if let Ok(data) = fs::read_to_string(file_path) {
-                self.tasks = serde_json::from_str(&data).unwrap_or_else(|_| Vec::new());
-            }
-        }
+        self.tasks = self.store.all_tasks().unwrap_or_default();
     }
 
-    pub fn add_task(&mut self, description: String, due_date: String) {
-        let id = self.tasks.len() as u32 + 1;
-        let created_at = Local::now().format("%Y-%m-%d").to_string();
+    pub fn add_task(&mut self, description: String, due_date_input: String) -> Result<(), String> {
+        let id = Uuid::new_v4();
+        let today = Local::now().date_naive();
+        let created_at = today.format("%Y-%m-%d").to_string();
+        let date = due_date::parse_due_date(&due_date_input, today)
+            .ok_or_else(|| format!("couldn't understand due date \"{}\"", due_date_input))?;
         let task = Task {
             id,
             description,
             created_at,
-            due_date,
-            status: "TODO".to_string(),
+            due_date: Some(DueDate {
+                raw: due_date_input,
+                date,
+            }),
+            status: self.status_name(0).to_string(),
+            priority: Priority::default(),
+            tags: Vec::new(),
+            time_entries: Vec::new(),
+            dependencies: HashSet::new(),
         };
+        if let Err(err) = self.store.upsert_task(&task) {
+            eprintln!("Failed to save task: {}", err);
+        }
         self.tasks.push(task);
+        Ok(())
     }
 
-    pub fn move_task(&mut self, direction: isize) {
-        let statuses = ["TODO", "DOING", "DONE"];
-        if let Some(task) = self
-            .tasks
-            .iter_mut()
-            .find(|t| t.status == statuses[self.selected_status])
-        {
-            let new_status_index = (self.selected_status as isize + direction)
-                .clamp(0, statuses.len() as isize - 1) as usize;
-            task.status = statuses[new_status_index].to_string();
+    /// Moves the currently selected task by `direction` columns. Refuses to
+    /// move a task into the final column while any of its dependencies
+    /// haven't reached it themselves, and refuses to move into a column
+    /// that's already at its WIP limit.
+    pub fn move_task(&mut self, direction: isize) -> Result<(), String> {
+        let last_index = self.config.columns.len() - 1;
+        let current_status = self.status_name(self.selected_status).to_string();
+        let Some(task_id) = self
+            .get_tasks_by_status(&current_status)
+            .get(self.selected_task)
+            .map(|t| t.id)
+        else {
+            return Ok(());
+        };
+
+        let new_status_index =
+            (self.selected_status as isize + direction).clamp(0, last_index as isize) as usize;
+        let new_status = self.status_name(new_status_index).to_string();
+
+        // A clamped move that lands back on the current column isn't really a
+        // move, so the WIP/dependency gates (which the task's own presence
+        // would otherwise trip) don't apply.
+        if new_status_index != self.selected_status {
+            if let Some(limit) = self.config.columns[new_status_index].wip_limit {
+                if self.get_tasks_by_status(&new_status).len() >= limit {
+                    return Err(format!("{} is at its WIP limit ({})", new_status, limit));
+                }
+            }
+
+            if new_status_index == last_index {
+                if let Some(blocker) = self.first_unfinished_dependency(task_id) {
+                    return Err(format!("blocked by #{}", blocker));
+                }
+            }
+        }
+
+        if let Some(task) = self.tasks.iter_mut().find(|t| t.id == task_id) {
+            task.status = new_status;
+            if let Err(err) = self.store.upsert_task(task) {
+                eprintln!("Failed to save task: {}", err);
+            }
+        }
+        Ok(())
+    }
+
+    /// A dependency is "unfinished" until it reaches the workflow's final column.
+    fn first_unfinished_dependency(&self, task_id: Uuid) -> Option<Uuid> {
+        let done = self.status_name(self.config.columns.len() - 1);
+        let task = self.tasks.iter().find(|t| t.id == task_id)?;
+        task.dependencies
+            .iter()
+            .find(|dep_id| {
+                self.tasks
+                    .iter()
+                    .find(|t| t.id == **dep_id)
+                    .map(|t| t.status != done)
+                    .unwrap_or(false)
+            })
+            .copied()
+    }
+
+    /// Records that `task_id` depends on `depends_on`, rejecting the edge if
+    /// it would create a cycle in the dependency graph.
+    pub fn add_dependency(&mut self, task_id: Uuid, depends_on: Uuid) -> Result<(), String> {
+        if task_id == depends_on {
+            return Err("a task cannot depend on itself".to_string());
+        }
+        if !self.tasks.iter().any(|t| t.id == task_id) {
+            return Err(format!("task #{} not found", task_id));
+        }
+        if !self.tasks.iter().any(|t| t.id == depends_on) {
+            return Err(format!("task #{} not found", depends_on));
+        }
+        if self.reaches(depends_on, task_id) {
+            return Err("adding this dependency would create a cycle".to_string());
+        }
+
+        if let Some(task) = self.tasks.iter_mut().find(|t| t.id == task_id) {
+            task.dependencies.insert(depends_on);
+            if let Err(err) = self.store.upsert_task(task) {
+                eprintln!("Failed to save task: {}", err);
+            }
         }
+        Ok(())
+    }
+
+    /// DFS over the existing dependency graph: can `from` reach `to`?
+    fn reaches(&self, from: Uuid, to: Uuid) -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = vec![from];
+        while let Some(current) = stack.pop() {
+            if current == to {
+                return true;
+            }
+            if !visited.insert(current) {
+                continue;
+            }
+            if let Some(task) = self.tasks.iter().find(|t| t.id == current) {
+                stack.extend(task.dependencies.iter().copied());
+            }
+        }
+        false
     }
 
     pub fn get_tasks_by_status(&self, status: &str) -> Vec<&Task> {
-        self.tasks.iter().filter(|t| t.status == status).collect()
+        let mut tasks: Vec<&Task> = self.tasks.iter().filter(|t| t.status == status).collect();
+        tasks.sort_by_key(|t| std::cmp::Reverse(t.priority));
+        tasks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_board() -> KanbanBoard {
+        KanbanBoard {
+            store: Store::open(":memory:").unwrap(),
+            config: Config::default(),
+            tasks: Vec::new(),
+            selected_status: 0,
+            selected_task: 0,
+        }
+    }
+
+    fn bare_task(id: Uuid) -> Task {
+        Task {
+            id,
+            description: String::new(),
+            created_at: String::new(),
+            due_date: None,
+            status: "TODO".to_string(),
+            priority: Priority::default(),
+            tags: Vec::new(),
+            time_entries: Vec::new(),
+            dependencies: HashSet::new(),
+        }
+    }
+
+    #[test]
+    fn rejects_self_dependency() {
+        let mut board = test_board();
+        let a = Uuid::new_v4();
+        board.tasks.push(bare_task(a));
+
+        assert_eq!(board.add_dependency(a, a), Err("a task cannot depend on itself".to_string()));
+    }
+
+    #[test]
+    fn rejects_direct_two_node_cycle() {
+        let mut board = test_board();
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        board.tasks.push(bare_task(a));
+        board.tasks.push(bare_task(b));
+
+        assert_eq!(board.add_dependency(a, b), Ok(()));
+        assert_eq!(
+            board.add_dependency(b, a),
+            Err("adding this dependency would create a cycle".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_transitive_three_node_cycle() {
+        let mut board = test_board();
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let c = Uuid::new_v4();
+        board.tasks.push(bare_task(a));
+        board.tasks.push(bare_task(b));
+        board.tasks.push(bare_task(c));
+
+        // a -> b -> c
+        assert_eq!(board.add_dependency(a, b), Ok(()));
+        assert_eq!(board.add_dependency(b, c), Ok(()));
+        // c -> a would close the loop.
+        assert_eq!(
+            board.add_dependency(c, a),
+            Err("adding this dependency would create a cycle".to_string())
+        );
     }
 }
@@ -1,9 +1,16 @@
+mod app;
+mod config;
+mod due_date;
 mod kanban_board;
+mod store;
 mod task;
 mod ui;
 
+use crate::app::KanbanApp;
 use crate::kanban_board::KanbanBoard;
+use crate::task::Priority;
 use crate::ui::run_app;
+use clap::{Arg, Command};
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
@@ -12,8 +19,9 @@ use crossterm::{
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 use std::io;
+use uuid::Uuid;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn run_tui() -> Result<(), Box<dyn std::error::Error>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
@@ -23,7 +31,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut board = KanbanBoard::new();
     board.load_from_file();
 
-    let result = run_app(&mut terminal, &mut board);
+    let result = tokio::runtime::Runtime::new()?.block_on(run_app(&mut terminal, &mut board));
 
     disable_raw_mode()?;
     execute!(
@@ -41,3 +49,138 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     Ok(())
 }
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let matches = Command::new("kanban")
+        .version("1.0")
+        .author("Your Name")
+        .about("Command-line Kanban board application")
+        .subcommand(Command::new("init").about("Initialize Kanban in the current directory"))
+        .subcommand(
+            Command::new("add")
+                .about("Add a new task")
+                .arg(Arg::new("status").required(true).help("Task status"))
+                .arg(Arg::new("tag").required(true).help("Task tag"))
+                .arg(Arg::new("description").required(true).help("Task description"))
+                .arg(
+                    Arg::new("priority")
+                        .long("priority")
+                        .value_parser(["low", "medium", "high"])
+                        .default_value("low")
+                        .help("Task priority"),
+                )
+                .arg(
+                    Arg::new("due")
+                        .long("due")
+                        .help("Due date, e.g. tomorrow, next friday, 2026-08-20"),
+                ),
+        )
+        .subcommand(
+            Command::new("log")
+                .about("Log time spent on a task")
+                .arg(Arg::new("task-id").required(true).help("Task id"))
+                .arg(
+                    Arg::new("duration")
+                        .required(true)
+                        .help("Duration, e.g. 1h30m or 45m"),
+                )
+                .arg(Arg::new("message").help("Optional note about the logged time")),
+        )
+        .subcommand(
+            Command::new("depend")
+                .about("Mark a task as depending on another")
+                .arg(Arg::new("task-id").required(true).help("Task id"))
+                .arg(Arg::new("blocks-on-id").required(true).help("Id of the task it depends on")),
+        )
+        .subcommand(Command::new("show").about("Show the Kanban board"))
+        .subcommand(Command::new("list").about("List all tasks"))
+        .subcommand(Command::new("tags").about("List all tags"))
+        .subcommand(Command::new("stats").about("Show statistics"))
+        .subcommand(
+            Command::new("export")
+                .about("Export all tasks to a CSV file")
+                .arg(Arg::new("path").required(true).help("Destination CSV path")),
+        )
+        .subcommand(
+            Command::new("import")
+                .about("Import tasks from a CSV file")
+                .arg(Arg::new("path").required(true).help("Source CSV path")),
+        )
+        .get_matches();
+
+    // Handle subcommands
+    match matches.subcommand() {
+        Some(("init", _)) => {
+            KanbanApp::init(".")?;
+        }
+        Some(("add", sub_matches)) => {
+            let status = sub_matches.get_one::<String>("status").unwrap();
+            let tag = sub_matches.get_one::<String>("tag").unwrap();
+            let description = sub_matches.get_one::<String>("description").unwrap();
+            let priority = match sub_matches.get_one::<String>("priority").map(String::as_str) {
+                Some("medium") => Priority::Medium,
+                Some("high") => Priority::High,
+                _ => Priority::Low,
+            };
+            let due = sub_matches.get_one::<String>("due").map(String::as_str);
+            KanbanApp::add_task_interactive(status, tag, description, priority, due)?;
+        }
+        Some(("log", sub_matches)) => {
+            let task_id = sub_matches.get_one::<String>("task-id").unwrap();
+            let duration = sub_matches.get_one::<String>("duration").unwrap();
+            let message = sub_matches.get_one::<String>("message").cloned();
+            KanbanApp::log_time(task_id, duration, message)?;
+        }
+        Some(("depend", sub_matches)) => {
+            let task_id: Uuid = sub_matches
+                .get_one::<String>("task-id")
+                .unwrap()
+                .parse()
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "task-id must be a valid UUID"))?;
+            let blocks_on_id: Uuid = sub_matches
+                .get_one::<String>("blocks-on-id")
+                .unwrap()
+                .parse()
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "blocks-on-id must be a valid UUID"))?;
+
+            let mut board = KanbanBoard::new();
+            board.load_from_file();
+            board
+                .add_dependency(task_id, blocks_on_id)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+            board.save_to_file();
+        }
+        Some(("show", _)) => {
+            let app = KanbanApp::new(".kanban_config.json");
+            app.show_board();
+        }
+        Some(("list", _)) => {
+            let app = KanbanApp::new(".kanban_config.json");
+            app.list_tasks();
+        }
+        Some(("tags", _)) => {
+            let app = KanbanApp::new(".kanban_config.json");
+            app.list_tags();
+        }
+        Some(("stats", _)) => {
+            let app = KanbanApp::new(".kanban_config.json");
+            app.show_stats();
+        }
+        Some(("export", sub_matches)) => {
+            let path = sub_matches.get_one::<String>("path").unwrap();
+            let app = KanbanApp::new(".kanban_config.json");
+            let count = app.export_csv(path)?;
+            println!("Exported {} tasks to {}", count, path);
+        }
+        Some(("import", sub_matches)) => {
+            let path = sub_matches.get_one::<String>("path").unwrap();
+            let app = KanbanApp::new(".kanban_config.json");
+            let count = app.import_csv(path)?;
+            println!("Imported {} tasks from {}", count, path);
+        }
+        _ => {
+            run_tui()?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use crate::task::{DueDate, Task};
+use rusqlite::{params, Connection, Row};
+use uuid::Uuid;
+
+const CURRENT_SCHEMA_VERSION: i64 = 1;
+
+const MIGRATION_1: &str = "
+    CREATE TABLE tasks (
+        id BLOB PRIMARY KEY,
+        description TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        due_date TEXT,
+        due_date_raw TEXT,
+        status TEXT NOT NULL,
+        priority TEXT NOT NULL,
+        tags TEXT NOT NULL,
+        time_entries TEXT NOT NULL,
+        dependencies TEXT NOT NULL
+    );
+";
+
+/// The single SQLite-backed home for tasks, shared by the CLI (`KanbanApp`)
+/// and the TUI (`KanbanBoard`) so the two no longer drift out of sync.
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: &str) -> rusqlite::Result<Self> {
+        let conn = Connection::open(path)?;
+        migrate(&conn)?;
+        Ok(Self { conn })
+    }
+
+    pub fn all_tasks(&self) -> rusqlite::Result<Vec<Task>> {
+        let mut stmt = self.conn.prepare("SELECT * FROM tasks ORDER BY id")?;
+        let tasks = stmt
+            .query_map([], row_to_task)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(tasks)
+    }
+
+    pub fn find_task(&self, id: Uuid) -> rusqlite::Result<Option<Task>> {
+        self.conn
+            .query_row("SELECT * FROM tasks WHERE id = ?1", params![id], row_to_task)
+            .map(Some)
+            .or_else(|err| match err {
+                rusqlite::Error::QueryReturnedNoRows => Ok(None),
+                other => Err(other),
+            })
+    }
+
+    pub fn upsert_task(&self, task: &Task) -> rusqlite::Result<()> {
+        self.conn.execute(
+            "INSERT INTO tasks
+                (id, description, created_at, due_date, due_date_raw, status, priority, tags, time_entries, dependencies)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+             ON CONFLICT(id) DO UPDATE SET
+                description = excluded.description,
+                created_at = excluded.created_at,
+                due_date = excluded.due_date,
+                due_date_raw = excluded.due_date_raw,
+                status = excluded.status,
+                priority = excluded.priority,
+                tags = excluded.tags,
+                time_entries = excluded.time_entries,
+                dependencies = excluded.dependencies",
+            params![
+                task.id,
+                task.description,
+                task.created_at,
+                task.due_date.as_ref().map(|d| d.date.to_string()),
+                task.due_date.as_ref().map(|d| d.raw.clone()),
+                task.status,
+                format!("{:?}", task.priority),
+                serde_json::to_string(&task.tags).unwrap_or_default(),
+                serde_json::to_string(&task.time_entries).unwrap_or_default(),
+                serde_json::to_string(&task.dependencies).unwrap_or_default(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn replace_all(&self, tasks: &[Task]) -> rusqlite::Result<()> {
+        for task in tasks {
+            self.upsert_task(task)?;
+        }
+        Ok(())
+    }
+}
+
+fn migrate(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch("CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL);")?;
+    let version: i64 = conn
+        .query_row("SELECT version FROM schema_version LIMIT 1", [], |row| row.get(0))
+        .unwrap_or(0);
+
+    if version < 1 {
+        conn.execute_batch(MIGRATION_1)?;
+    }
+
+    conn.execute("DELETE FROM schema_version", [])?;
+    conn.execute(
+        "INSERT INTO schema_version (version) VALUES (?1)",
+        params![CURRENT_SCHEMA_VERSION],
+    )?;
+    Ok(())
+}
+
+fn row_to_task(row: &Row) -> rusqlite::Result<Task> {
+    let priority_text: String = row.get("priority")?;
+    let priority = match priority_text.as_str() {
+        "Medium" => crate::task::Priority::Medium,
+        "High" => crate::task::Priority::High,
+        _ => crate::task::Priority::Low,
+    };
+
+    let due_date: Option<String> = row.get("due_date")?;
+    let due_date_raw: Option<String> = row.get("due_date_raw")?;
+    let due_date = match (due_date, due_date_raw) {
+        (Some(date), Some(raw)) => date.parse().ok().map(|date| DueDate { raw, date }),
+        _ => None,
+    };
+
+    let tags_json: String = row.get("tags")?;
+    let time_entries_json: String = row.get("time_entries")?;
+    let dependencies_json: String = row.get("dependencies")?;
+
+    Ok(Task {
+        id: row.get("id")?,
+        description: row.get("description")?,
+        created_at: row.get("created_at")?,
+        due_date,
+        status: row.get("status")?,
+        priority,
+        tags: serde_json::from_str(&tags_json).unwrap_or_default(),
+        time_entries: serde_json::from_str(&time_entries_json).unwrap_or_default(),
+        dependencies: serde_json::from_str(&dependencies_json).unwrap_or_default(),
+    })
+}
@@ -1,10 +1,80 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// How urgently a task needs attention. Ordered `Low < Medium < High` so
+/// tasks can be sorted with the most urgent work first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
+pub enum Priority {
+    #[default]
+    Low,
+    Medium,
+    High,
+}
+
+/// A single logged chunk of work against a task. `minutes` is always kept
+/// below 60; any overflow is rolled into `hours` on construction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntry {
+    pub logged_date: NaiveDate,
+    pub hours: u16,
+    pub minutes: u16,
+    pub message: Option<String>,
+}
+
+impl TimeEntry {
+    pub fn new(logged_date: NaiveDate, hours: u16, minutes: u16, message: Option<String>) -> Self {
+        let mut entry = Self {
+            logged_date,
+            hours,
+            minutes,
+            message,
+        };
+        entry.hours += entry.minutes / 60;
+        entry.minutes %= 60;
+        entry
+    }
+}
+
+/// A due date normalized for comparison, alongside the raw text the user
+/// typed (e.g. "next friday") so the board can still show it as entered.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DueDate {
+    pub raw: String,
+    pub date: NaiveDate,
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Task {
-    pub id: u32,
+    pub id: Uuid,
     pub description: String,
     pub created_at: String,
-    pub due_date: String,
-    pub status: String, // "TODO", "DOING", "DONE"
+    pub due_date: Option<DueDate>,
+    /// One of the workflow's configured column names (see `Config.columns`),
+    /// not a fixed set of values.
+    pub status: String,
+    #[serde(default)]
+    pub priority: Priority,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub time_entries: Vec<TimeEntry>,
+    /// IDs of tasks that must reach the workflow's final column (DONE by
+    /// default) before this one can.
+    #[serde(default)]
+    pub dependencies: HashSet<Uuid>,
+}
+
+impl Task {
+    /// Total time logged against this task, normalized to `minutes < 60`.
+    pub fn total_logged(&self) -> (u32, u32) {
+        let (hours, minutes) = self
+            .time_entries
+            .iter()
+            .fold((0u32, 0u32), |(h, m), entry| {
+                (h + entry.hours as u32, m + entry.minutes as u32)
+            });
+        (hours + minutes / 60, minutes % 60)
+    }
 }
@@ -1,48 +1,105 @@
 use crate::kanban_board::KanbanBoard;
+use crate::task::Priority;
+use chrono::Local;
 use crossterm::{
-    event::{self, Event, KeyCode},
+    event::{Event, EventStream, KeyCode},
     terminal::{disable_raw_mode, enable_raw_mode},
 };
+use futures_util::{FutureExt, StreamExt};
 use ratatui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
     Terminal,
 };
 use std::io;
+use std::time::Duration;
+use tokio::time::interval;
+
+/// Drives the board as a live dashboard: a 1s tick keeps it redrawing (and
+/// overdue tasks flagged) even when the user isn't pressing keys, alongside
+/// the usual keyboard-driven input.
+pub async fn run_app<B: Backend>(terminal: &mut Terminal<B>, board: &mut KanbanBoard) -> io::Result<()> {
+    let mut status_message: Option<String> = None;
+    let mut events = EventStream::new();
+    let mut ticker = interval(Duration::from_secs(1));
 
-pub fn run_app<B: Backend>(terminal: &mut Terminal<B>, board: &mut KanbanBoard) -> io::Result<()> {
-    let statuses = ["TODO", "DOING", "DONE"];
     loop {
+        let statuses: Vec<String> = board.column_names().into_iter().map(String::from).collect();
+        let done_status = statuses.last().cloned().unwrap_or_default();
+        let today = Local::now().date_naive();
+        let overdue_count = board
+            .tasks
+            .iter()
+            .filter(|t| t.status != done_status && t.due_date.as_ref().is_some_and(|d| d.date < today))
+            .count();
+
         terminal.draw(|f| {
+            let outer = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(vec![Constraint::Min(0), Constraint::Length(4)])
+                .split(f.area());
+
+            let constraints = vec![Constraint::Ratio(1, statuses.len() as u32); statuses.len()];
             let chunks = Layout::default()
                 .direction(Direction::Horizontal)
-                .constraints(vec![
-                    Constraint::Percentage(33),
-                    Constraint::Percentage(33),
-                    Constraint::Percentage(34),
-                ])
-                .split(f.area());
+                .constraints(constraints)
+                .split(outer[0]);
 
             for (i, status) in statuses.iter().enumerate() {
                 let tasks = board.get_tasks_by_status(status);
                 let items: Vec<ListItem> = tasks
                     .iter()
                     .map(|t| {
-                        ListItem::new(Line::from(vec![
+                        let overdue = *status != done_status
+                            && t.due_date.as_ref().is_some_and(|d| d.date < today);
+                        let color = if overdue {
+                            Color::Red
+                        } else {
+                            match t.priority {
+                                Priority::Low => Color::Green,
+                                Priority::Medium => Color::Yellow,
+                                Priority::High => Color::Red,
+                            }
+                        };
+                        let blocker = t.dependencies.iter().find(|dep_id| {
+                            board
+                                .tasks
+                                .iter()
+                                .find(|other| other.id == **dep_id)
+                                .map(|other| other.status != done_status)
+                                .unwrap_or(false)
+                        });
+                        let mut spans = vec![
                             Span::raw(format!("[#{}] ", t.id)),
-                            Span::styled(&t.description, Style::default().fg(Color::White)),
-                            Span::raw(format!(" (Due: {})", t.due_date)),
-                        ]))
+                            Span::styled(&t.description, Style::default().fg(color)),
+                            Span::raw(format!(
+                                " (Due: {})",
+                                t.due_date.as_ref().map_or("none", |d| d.raw.as_str())
+                            )),
+                        ];
+                        if overdue {
+                            spans.push(Span::styled(
+                                " OVERDUE",
+                                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                            ));
+                        }
+                        if let Some(blocker) = blocker {
+                            spans.push(Span::styled(
+                                format!(" \u{26d4} blocked by #{}", blocker),
+                                Style::default().fg(Color::Red),
+                            ));
+                        }
+                        ListItem::new(Line::from(spans))
                     })
                     .collect();
 
                 let list = List::new(items)
                     .block(
                         Block::default()
-                            .title(*status)
+                            .title(status.as_str())
                             .borders(Borders::ALL)
                             .border_style(if board.selected_status == i {
                                 Style::default().fg(Color::Cyan)
@@ -54,46 +111,81 @@ pub fn run_app<B: Backend>(terminal: &mut Terminal<B>, board: &mut KanbanBoard)
 
                 f.render_widget(list, chunks[i]);
             }
+
+            let selected = board
+                .get_tasks_by_status(&statuses[board.selected_status])
+                .get(board.selected_task)
+                .copied();
+            let time_info = match selected {
+                Some(task) => {
+                    let (hours, minutes) = task.total_logged();
+                    format!("Task #{}: {}h{}m logged", task.id, hours, minutes)
+                }
+                None => "No task selected".to_string(),
+            };
+            let overdue_info = format!("Overdue: {}", overdue_count);
+            let status_line = status_message.clone().unwrap_or_default();
+            f.render_widget(
+                Paragraph::new(vec![
+                    Line::from(time_info),
+                    Line::from(overdue_info),
+                    Line::from(status_line),
+                ])
+                .block(Block::default().borders(Borders::ALL).title("Status")),
+                outer[1],
+            );
         })?;
 
-        if let Event::Key(key) = event::read()? {
-            match key.code {
-                KeyCode::Char('q') => return Ok(()), // Quit
-                KeyCode::Char('a') => {
-                    // Add a new task
-                    if let Some(description) = prompt("Enter task description") {
-                        if let Some(due_date) = prompt("Enter due date (YYYY-MM-DD)") {
-                            board.add_task(description, due_date);
+        tokio::select! {
+            _ = ticker.tick() => {}
+            event = events.next().fuse() => {
+                match event {
+                    Some(Ok(Event::Key(key))) => {
+                        status_message = None;
+                        match key.code {
+                            KeyCode::Char('q') => return Ok(()), // Quit
+                            KeyCode::Char('a') => {
+                                // Add a new task
+                                if let Some(description) = prompt("Enter task description") {
+                                    if let Some(due_date) =
+                                        prompt("Enter due date (e.g. tomorrow, next friday, 2026-08-20)")
+                                    {
+                                        if let Err(err) = board.add_task(description, due_date) {
+                                            status_message = Some(err);
+                                        }
+                                    }
+                                }
+                            }
+                            KeyCode::Left if board.selected_status > 0 => {
+                                board.selected_status -= 1;
+                            }
+                            KeyCode::Right if board.selected_status < statuses.len() - 1 => {
+                                board.selected_status += 1;
+                            }
+                            KeyCode::Up if board.selected_task > 0 => {
+                                board.selected_task -= 1;
+                            }
+                            KeyCode::Down => {
+                                let max_tasks = board
+                                    .get_tasks_by_status(&statuses[board.selected_status])
+                                    .len();
+                                if max_tasks > 0 && board.selected_task < max_tasks - 1 {
+                                    board.selected_task += 1;
+                                }
+                            }
+                            KeyCode::Enter => {
+                                // Move to the next status
+                                if let Err(err) = board.move_task(1) {
+                                    status_message = Some(err);
+                                }
+                            }
+                            _ => {}
                         }
                     }
+                    Some(Ok(_)) => {}
+                    Some(Err(err)) => return Err(err),
+                    None => return Ok(()),
                 }
-                KeyCode::Left => {
-                    if board.selected_status > 0 {
-                        board.selected_status -= 1;
-                    }
-                }
-                KeyCode::Right => {
-                    if board.selected_status < statuses.len() - 1 {
-                        board.selected_status += 1;
-                    }
-                }
-                KeyCode::Up => {
-                    if board.selected_task > 0 {
-                        board.selected_task -= 1;
-                    }
-                }
-                KeyCode::Down => {
-                    let max_tasks = board
-                        .get_tasks_by_status(statuses[board.selected_status])
-                        .len();
-                    if board.selected_task < max_tasks - 1 {
-                        board.selected_task += 1;
-                    }
-                }
-                KeyCode::Enter => {
-                    board.move_task(1); // Move to the next status
-                }
-                _ => {}
             }
         }
     }